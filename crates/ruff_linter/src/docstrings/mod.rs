@@ -1,8 +1,10 @@
+use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
 use std::ops::Deref;
+use std::str::Chars;
 
 use ruff_python_ast::{self as ast, StringFlags};
-use ruff_python_semantic::Definition;
+use ruff_python_semantic::{Definition, MemberKind};
 use ruff_source_file::LineRanges;
 use ruff_text_size::{Ranged, TextRange, TextSize};
 
@@ -67,20 +69,56 @@ impl<'a> Docstring<'a> {
         // N.B. This will normally be exactly the same as what you might get from
         // `self.flags().prefix().as_str()`, but doing it this way has a few small advantages.
         // For example, the casing of the `u` prefix will be preserved if it's a u-string.
-        &self.source[TextRange::new(
-            self.start(),
-            self.start() + self.flags().prefix().text_len(),
-        )]
+        &self.source[self.offsets().prefix]
     }
 
     /// The docstring's "opener" (the string's prefix, if any, and its opening quotes).
     pub fn opener(&self) -> &'a str {
-        &self.source[TextRange::new(self.start(), self.start() + self.flags().opener_len())]
+        &self.source[self.offsets().opener]
     }
 
     /// The docstring's closing quotes.
     pub fn closer(&self) -> &'a str {
-        &self.source[TextRange::new(self.end() - self.flags().closer_len(), self.end())]
+        &self.source[self.offsets().closer]
+    }
+
+    /// Classifies the docstring by the kind of node it documents, so that section/style checks
+    /// can branch on a single semantic classifier instead of re-inspecting `self.definition`
+    /// each time.
+    ///
+    /// `DocstringKind::Attribute` is never returned here: [`Definition`] only models module,
+    /// class, and function/method docstrings today, so attribute docstrings must be recognized
+    /// by the extraction step that walks assignments before a [`Docstring`] is constructed.
+    pub fn kind(&self) -> DocstringKind {
+        match self.definition {
+            Definition::Module(_) => DocstringKind::Module,
+            Definition::Member(member) => match &member.kind {
+                MemberKind::Class(_) | MemberKind::NestedClass(_) => DocstringKind::Class,
+                MemberKind::Function(_) | MemberKind::NestedFunction(_) | MemberKind::Method(_) => {
+                    DocstringKind::FunctionOrMethod
+                }
+            },
+        }
+    }
+
+    /// Computes the [`DocstringOffsets`]: the prefix, opening-quote, content, and closing-quote
+    /// ranges, in a single pass over the docstring's [`flags`](Self::flags).
+    ///
+    /// [`Docstring::prefix_str`], [`Docstring::opener`], [`Docstring::body`], and
+    /// [`Docstring::closer`] are implemented on top of this so there's a single source of truth
+    /// for the four ranges. Call `offsets()` directly when a call site needs more than one of
+    /// them together (e.g. fix logic rewriting the quote spans without touching the body).
+    pub fn offsets(&self) -> DocstringOffsets {
+        let flags = self.flags();
+        let start = self.start();
+        let end = self.end();
+
+        DocstringOffsets {
+            prefix: TextRange::new(start, start + flags.prefix().text_len()),
+            opener: TextRange::new(start, start + flags.opener_len()),
+            content: self.expr.content_range(),
+            closer: TextRange::new(end - flags.closer_len(), end),
+        }
     }
 }
 
@@ -90,6 +128,36 @@ impl Ranged for Docstring<'_> {
     }
 }
 
+/// The kind of node a [`Docstring`] documents.
+///
+/// This mirrors the distinction rust-analyzer draws between doc comment placements, adapted to
+/// Python's docstring conventions (PEP 257) rather than Rust's `///`/`//!` comments.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DocstringKind {
+    /// A module's docstring, i.e. the first statement in a module.
+    Module,
+    /// A class's docstring, i.e. the first statement in a `class` body.
+    Class,
+    /// A function's or method's docstring, i.e. the first statement in a `def` body.
+    FunctionOrMethod,
+    /// A PEP 258 attribute docstring: a bare string literal immediately following an
+    /// assignment to a module or class attribute.
+    Attribute,
+}
+
+/// The ranges of the four parts of a docstring: the prefix (e.g. `r`), the opening quotes,
+/// the content between the quotes, and the closing quotes. Computed once by
+/// [`Docstring::offsets`], which backs the individual accessors ([`Docstring::prefix_str`],
+/// [`Docstring::opener`], [`Docstring::body`], [`Docstring::closer`]) so the four formulas live
+/// in one place; call `offsets()` directly when more than one range is needed at once.
+#[derive(Debug, Copy, Clone)]
+pub struct DocstringOffsets {
+    pub prefix: TextRange,
+    pub opener: TextRange,
+    pub content: TextRange,
+    pub closer: TextRange,
+}
+
 #[derive(Copy, Clone)]
 pub struct DocstringBody<'a> {
     docstring: &'a Docstring<'a>,
@@ -99,11 +167,242 @@ impl<'a> DocstringBody<'a> {
     pub fn as_str(self) -> &'a str {
         &self.docstring.source[self.range()]
     }
+
+    /// Returns the docstring body with Python escape sequences (e.g. `\n`, `\t`, `\xhh`)
+    /// resolved into the characters they represent.
+    ///
+    /// Raw strings (`r"""..."""`) are returned unchanged, since escape sequences are not
+    /// processed in raw string literals. For all other strings, this only allocates when the
+    /// body actually contains an escape sequence; a body with no backslashes is returned
+    /// as a borrowed slice of the source.
+    ///
+    /// Named Unicode escapes (`\N{SNOWMAN}`) are **not** resolved: doing so requires a Unicode
+    /// character name database that isn't available here, so `\N{...}` is passed through
+    /// verbatim rather than decoded. Callers inspecting decoded text (e.g. checking whether a
+    /// docstring ends with a period) will see the raw escape in that case, not the character it
+    /// names.
+    pub fn to_decoded(self) -> Cow<'a, str> {
+        if self.docstring.is_raw_string() {
+            return Cow::Borrowed(self.as_str());
+        }
+
+        decode_escapes(self.as_str())
+    }
+
+    /// Returns the docstring body normalized the way the [PEP 257 `trim` recipe] normalizes it:
+    /// the first line's leading whitespace is stripped, the common leading whitespace is
+    /// removed from every subsequent non-blank line, and leading and trailing blank lines and
+    /// trailing whitespace on every line are dropped.
+    ///
+    /// This follows `trim`, not [`inspect.cleandoc`], on one point: `cleandoc` leaves trailing
+    /// whitespace on each line untouched, while `trim` (and this function) strips it.
+    ///
+    /// [PEP 257 `trim` recipe]: https://peps.python.org/pep-0257/#handling-docstring-indentation
+    /// [`inspect.cleandoc`]: https://docs.python.org/3/library/inspect.html#inspect.cleandoc
+    pub fn clean(self) -> String {
+        clean_text(self.as_str())
+    }
+}
+
+/// Normalizes `text` the way the [PEP 257 `trim` recipe] normalizes a docstring body. See
+/// [`DocstringBody::clean`] for the full behavior, including its one deliberate divergence from
+/// [`inspect.cleandoc`].
+///
+/// [PEP 257 `trim` recipe]: https://peps.python.org/pep-0257/#handling-docstring-indentation
+/// [`inspect.cleandoc`]: https://docs.python.org/3/library/inspect.html#inspect.cleandoc
+fn clean_text(text: &str) -> String {
+    let mut lines = text.lines();
+
+    let first_line = lines.next().map(str::trim_start);
+
+    // Find the minimum indentation of all non-blank lines after the first, treating tabs
+    // as expanding to the next multiple of 8 columns (matching `str.expandtabs()`).
+    let indentation = lines
+        .clone()
+        .filter(|line| !line.trim().is_empty())
+        .map(expanded_indentation)
+        .min()
+        .unwrap_or(0);
+
+    let mut cleaned_lines: Vec<Cow<'_, str>> = Vec::new();
+    if let Some(first_line) = first_line {
+        cleaned_lines.push(Cow::Borrowed(first_line));
+    }
+    for line in lines {
+        cleaned_lines.push(strip_indentation(line, indentation));
+    }
+
+    // Strip leading and trailing blank lines.
+    while cleaned_lines.first().is_some_and(|line| line.trim().is_empty()) {
+        cleaned_lines.remove(0);
+    }
+    while cleaned_lines.last().is_some_and(|line| line.trim().is_empty()) {
+        cleaned_lines.pop();
+    }
+
+    cleaned_lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the indentation of `line` in columns, expanding tabs to the next multiple of 8
+/// (matching Python's `str.expandtabs()`, which `inspect.cleandoc` relies on).
+fn expanded_indentation(line: &str) -> usize {
+    let mut columns = 0;
+    for c in line.chars() {
+        match c {
+            ' ' => columns += 1,
+            '\t' => columns += 8 - (columns % 8),
+            _ => break,
+        }
+    }
+    columns
+}
+
+/// Removes up to `indentation` columns of leading whitespace from `line`, expanding the whole
+/// leading whitespace run to spaces first (as [`str.expandtabs()`] and `inspect.cleandoc` do)
+/// rather than returning any of its tabs unexpanded, so that e.g. a margin that lands exactly
+/// on a tab stop still expands any further tabs in the run into the spaces they represent.
+///
+/// [`str.expandtabs()`]: https://docs.python.org/3/library/stdtypes.html#str.expandtabs
+fn strip_indentation(line: &str, indentation: usize) -> Cow<'_, str> {
+    let mut columns = 0;
+    let mut whitespace_end = 0;
+
+    for (index, c) in line.char_indices() {
+        match c {
+            ' ' => columns += 1,
+            '\t' => columns += 8 - (columns % 8),
+            _ => break,
+        }
+        whitespace_end = index + c.len_utf8();
+    }
+
+    let rest = &line[whitespace_end..];
+    if columns <= indentation {
+        return Cow::Borrowed(rest);
+    }
+
+    let mut stripped = " ".repeat(columns - indentation);
+    stripped.push_str(rest);
+    Cow::Owned(stripped)
+}
+
+/// Resolves Python escape sequences in `text` into the characters they represent. Only
+/// allocates when `text` actually contains an escape sequence; otherwise `text` is returned
+/// as a borrowed `Cow`.
+fn decode_escapes(text: &str) -> Cow<'_, str> {
+    if !text.contains('\\') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            decoded.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('\n') => {}
+            Some('n') => decoded.push('\n'),
+            Some('t') => decoded.push('\t'),
+            Some('r') => decoded.push('\r'),
+            Some('\\') => decoded.push('\\'),
+            Some('\'') => decoded.push('\''),
+            Some('"') => decoded.push('"'),
+            Some('a') => decoded.push('\u{7}'),
+            Some('b') => decoded.push('\u{8}'),
+            Some('f') => decoded.push('\u{c}'),
+            Some('v') => decoded.push('\u{b}'),
+            Some(digit @ '0'..='7') => {
+                decoded.push(decode_octal_escape(digit, &mut chars));
+            }
+            Some('x') => {
+                if let Some(decoded_char) = decode_hex_escape(&mut chars, 2) {
+                    decoded.push(decoded_char);
+                } else {
+                    decoded.push('\\');
+                    decoded.push('x');
+                }
+            }
+            Some('u') => {
+                if let Some(decoded_char) = decode_hex_escape(&mut chars, 4) {
+                    decoded.push(decoded_char);
+                } else {
+                    decoded.push('\\');
+                    decoded.push('u');
+                }
+            }
+            Some('U') => {
+                if let Some(decoded_char) = decode_hex_escape(&mut chars, 8) {
+                    decoded.push(decoded_char);
+                } else {
+                    decoded.push('\\');
+                    decoded.push('U');
+                }
+            }
+            Some('N') => {
+                // Named Unicode escapes (`\N{SNOWMAN}`) require a Unicode character
+                // database lookup that isn't available here, so the escape is emitted
+                // verbatim rather than silently dropped. See `DocstringBody::to_decoded`.
+                decoded.push('\\');
+                decoded.push('N');
+            }
+            Some(other) => {
+                decoded.push('\\');
+                decoded.push(other);
+            }
+            None => decoded.push('\\'),
+        }
+    }
+
+    Cow::Owned(decoded)
+}
+
+/// Decodes a `\ooo` octal escape (1-3 octal digits, the first of which has already been
+/// consumed) into its character value.
+fn decode_octal_escape(first_digit: char, chars: &mut Chars<'_>) -> char {
+    let mut value = first_digit.to_digit(8).unwrap();
+
+    for _ in 0..2 {
+        let mut lookahead = chars.clone();
+        let Some(digit) = lookahead.next().and_then(|c| c.to_digit(8)) else {
+            break;
+        };
+        value = value * 8 + digit;
+        *chars = lookahead;
+    }
+
+    char::from_u32(value).unwrap_or('\u{FFFD}')
+}
+
+/// Decodes exactly `len` hexadecimal digits following a `\x`, `\u`, or `\U` escape into the
+/// character value they encode, or `None` if the escape is malformed.
+fn decode_hex_escape(chars: &mut Chars<'_>, len: usize) -> Option<char> {
+    let mut lookahead = chars.clone();
+    let mut value = 0u32;
+
+    for _ in 0..len {
+        let digit = lookahead.next()?.to_digit(16)?;
+        value = value * 16 + digit;
+    }
+
+    // Only consume the digits once they're confirmed to form a valid scalar value (e.g. not a
+    // lone surrogate like `D800`); otherwise the caller's fallback would re-emit the `\x`/`\u`/
+    // `\U` marker while silently dropping the digits themselves from the source.
+    let decoded = char::from_u32(value)?;
+    *chars = lookahead;
+    Some(decoded)
 }
 
 impl Ranged for DocstringBody<'_> {
     fn range(&self) -> TextRange {
-        self.docstring.expr.content_range()
+        self.docstring.offsets().content
     }
 }
 
@@ -123,3 +422,96 @@ impl Debug for DocstringBody<'_> {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{clean_text, decode_escapes};
+
+    #[test]
+    fn decode_escapes_no_escapes_is_borrowed() {
+        let decoded = decode_escapes("no escapes here");
+        assert!(matches!(decoded, std::borrow::Cow::Borrowed(_)));
+        assert_eq!(&*decoded, "no escapes here");
+    }
+
+    #[test]
+    fn decode_escapes_common_escapes() {
+        assert_eq!(&*decode_escapes(r#"a\nb\tc\\d\"e"#), "a\nb\tc\\d\"e");
+    }
+
+    #[test]
+    fn decode_escapes_octal() {
+        // `\012` is decimal 10, a newline; `\040` is decimal 32, a space.
+        assert_eq!(&*decode_escapes(r"\012"), "\n");
+        assert_eq!(&*decode_escapes(r"\040"), " ");
+        assert_eq!(&*decode_escapes(r"\0"), "\0");
+        assert_eq!(&*decode_escapes(r"\7"), "\u{7}");
+    }
+
+    #[test]
+    fn decode_escapes_hex_and_unicode() {
+        assert_eq!(&*decode_escapes(r"\x41"), "A");
+        assert_eq!(&*decode_escapes(r"\u0041"), "A");
+        assert_eq!(&*decode_escapes(r"\U00000041"), "A");
+    }
+
+    #[test]
+    fn decode_escapes_invalid_scalar_preserves_digits() {
+        // `D800` is a lone surrogate, not a valid Unicode scalar value: the escape can't be
+        // decoded, so the original digits must survive unchanged rather than being dropped.
+        assert_eq!(&*decode_escapes(r"\uD800abc"), r"\uD800abc");
+    }
+
+    #[test]
+    fn decode_escapes_named_unicode_is_passed_through() {
+        // `\N{...}` isn't resolved (no Unicode name database available); it's emitted verbatim.
+        assert_eq!(&*decode_escapes(r"\N{SNOWMAN}"), r"\N{SNOWMAN}");
+    }
+
+    #[test]
+    fn decode_escapes_line_continuation_is_dropped() {
+        assert_eq!(&*decode_escapes("a\\\nb"), "ab");
+    }
+
+    #[test]
+    fn clean_text_strips_common_indentation() {
+        assert_eq!(
+            clean_text("Summary.\n\n    Body line one.\n    Body line two.\n    "),
+            "Summary.\n\nBody line one.\nBody line two."
+        );
+    }
+
+    #[test]
+    fn clean_text_strips_leading_and_trailing_blank_lines() {
+        assert_eq!(clean_text("\n\n    Text.\n\n\n"), "Text.");
+    }
+
+    #[test]
+    fn clean_text_strips_trailing_whitespace_per_line() {
+        // Intentional divergence from `inspect.cleandoc`: this follows the PEP 257 `trim`
+        // recipe, which `.rstrip()`s every line (and `.strip()`s the first).
+        assert_eq!(clean_text("Summary.   \n\n    Body.  \n"), "Summary.\n\nBody.");
+    }
+
+    #[test]
+    fn clean_text_mixed_tabs_and_spaces_preserves_relative_indentation() {
+        // A tab expands to column 8; a margin of 4 (from the 4-space line) leaves 4 columns of
+        // the tab's indentation behind as literal spaces, preserving the nested block's
+        // indentation relative to the rest of the docstring.
+        assert_eq!(
+            clean_text("Summary.\n\n\tfoo\n    bar\n"),
+            "Summary.\n\n    foo\nbar"
+        );
+    }
+
+    #[test]
+    fn clean_text_tab_exactly_at_margin_is_fully_consumed() {
+        // Matches `inspect.cleandoc("Summary.\n\n\t\tfoo\n\tbar\n")` in CPython: the margin (one
+        // tab stop, 8 columns) is removed from the fully tab-expanded indentation, so the
+        // second tab in `"\t\tfoo"` survives as 8 literal spaces rather than as a raw tab.
+        assert_eq!(
+            clean_text("Summary.\n\n\t\tfoo\n\tbar\n"),
+            "Summary.\n\n        foo\nbar"
+        );
+    }
+}